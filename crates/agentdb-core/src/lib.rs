@@ -0,0 +1,119 @@
+//! # agentdb-core - Driver-free database abstraction layer for AI agents
+//!
+//! `agentdb-core` provides the unified traits for agent storage operations —
+//! CRUD, transactions, queries, scans — without depending on any concrete
+//! backend. Backend families (SQL, KV, Graph) ship as separate,
+//! feature-gated driver crates (`agentdb-sql`, `agentdb-kv`, `agentdb-graph`)
+//! that depend on this crate; the top-level `agentdb` crate re-exports this
+//! API and wires the driver crates together behind Cargo features.
+//!
+//! ## Architecture
+//!
+//! ```text
+//! AgentFS → agentdb (connect/registry) → agentdb-core (traits) ← (agentdb-sql | agentdb-kv | agentdb-graph)
+//! ```
+//!
+//! ## Core Traits
+//!
+//! - [`AgentDB`]: Main trait for database operations (CRUD, transactions, queries)
+//! - [`Capabilities`]: Describes backend capabilities (transactions, indexes, etc.)
+//! - [`Transaction`]: Transaction management interface
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use agentdb_core::{AgentDB, Capabilities};
+//!
+//! async fn example(db: impl AgentDB) -> Result<(), Box<dyn std::error::Error>> {
+//!     // Check capabilities
+//!     if db.capabilities().supports_transactions() {
+//!         let tx = db.begin().await?;
+//!         db.put("key", b"value").await?;
+//!         tx.commit().await?;
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use std::fmt;
+
+pub mod error;
+pub mod migrate;
+pub mod pool;
+pub mod prune;
+pub mod statement;
+pub mod traits;
+pub mod transaction;
+
+pub use error::{AgentDbError, Result};
+pub use migrate::{Migration, MigrationStatus, Migrator};
+pub use pool::{Pool, PoolBuilder, PooledConnection};
+pub use prune::PruningMode;
+pub use statement::{Statement, StatementCache};
+pub use traits::{AgentDB, Capabilities, DefaultCapabilities, Row, Transaction, QueryResult, ScanResult};
+pub use transaction::TransactionDepth;
+
+/// Backend family identifier
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BackendFamily {
+    /// SQL-based backends (SQLite, PostgreSQL, MySQL)
+    Sql,
+    /// Key-value backends (Redis, KeyDB, FoundationDB)
+    Kv,
+    /// Graph backends (Neo4j, Dgraph, TigerGraph)
+    Graph,
+}
+
+impl fmt::Display for BackendFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BackendFamily::Sql => write!(f, "SQL"),
+            BackendFamily::Kv => write!(f, "KeyValue"),
+            BackendFamily::Graph => write!(f, "Graph"),
+        }
+    }
+}
+
+/// Value type for database operations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Value(Vec<u8>);
+
+impl Value {
+    /// Create a new value from bytes
+    pub fn new(data: Vec<u8>) -> Self {
+        Self(data)
+    }
+
+    /// Create a value from a byte slice
+    pub fn from_slice(data: &[u8]) -> Self {
+        Self(data.to_vec())
+    }
+
+    /// Get the value as bytes
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Convert value into bytes
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl From<Vec<u8>> for Value {
+    fn from(data: Vec<u8>) -> Self {
+        Self(data)
+    }
+}
+
+impl From<&[u8]> for Value {
+    fn from(data: &[u8]) -> Self {
+        Self(data.to_vec())
+    }
+}
+
+impl AsRef<[u8]> for Value {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
@@ -0,0 +1,336 @@
+//! Embedded migration runner for SQL-capable backends.
+//!
+//! [`Migrator`] applies ordered, checksummed [`Migration`]s against any
+//! backend that supports SQL queries and transactions, tracking what has run
+//! in a `_agentdb_migrations` bookkeeping table — comparable to sqlx's
+//! `migrate` feature. Each migration is applied inside the
+//! [`AgentDB::transaction`] combinator, and a previously-applied migration
+//! whose checksum no longer matches its source is treated as drift and
+//! refused rather than silently re-applied.
+
+use crate::{AgentDB, AgentDbError, Result, Value};
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A single ordered schema migration.
+#[derive(Debug, Clone)]
+pub struct Migration {
+    /// Monotonically increasing version; migrations apply in ascending order.
+    pub version: i64,
+    /// Human-readable description, stored alongside the tracking row.
+    pub description: String,
+    /// The SQL to execute.
+    pub sql: String,
+    /// SHA-256 checksum of `sql`, used to detect drift in already-applied migrations.
+    pub checksum: [u8; 32],
+}
+
+impl Migration {
+    /// Build a migration, computing its checksum from `sql`.
+    pub fn new(version: i64, description: impl Into<String>, sql: impl Into<String>) -> Self {
+        let sql = sql.into();
+        let checksum = sha256(sql.as_bytes());
+        Self {
+            version,
+            description: description.into(),
+            sql,
+            checksum,
+        }
+    }
+
+    /// Parse a migration from a `V{version}__{description}.sql` filename and its contents.
+    fn from_file(file_name: &str, sql: String) -> Option<Self> {
+        let name = file_name.strip_prefix('V')?;
+        let (version, rest) = name.split_once("__")?;
+        let version: i64 = version.parse().ok()?;
+        let description = rest.strip_suffix(".sql").unwrap_or(rest).replace('_', " ");
+        Some(Self::new(version, description, sql))
+    }
+}
+
+/// Applied vs. pending migrations, as reported by [`Migrator::status`].
+#[derive(Debug, Clone, Default)]
+pub struct MigrationStatus {
+    /// Versions recorded in `_agentdb_migrations`.
+    pub applied: Vec<i64>,
+    /// Versions known to this `Migrator` but not yet recorded.
+    pub pending: Vec<i64>,
+}
+
+/// Applies an ordered set of [`Migration`]s against an [`AgentDB`] backend.
+pub struct Migrator {
+    migrations: Vec<Migration>,
+}
+
+impl Migrator {
+    /// Build a migrator from an explicit, embedded set of migrations.
+    ///
+    /// The migrations are sorted by `version` regardless of input order.
+    pub fn new(mut migrations: Vec<Migration>) -> Self {
+        migrations.sort_by_key(|m| m.version);
+        Self { migrations }
+    }
+
+    /// Build a migrator by reading `V{version}__{description}.sql` files from a directory.
+    pub fn from_dir(dir: &Path) -> Result<Self> {
+        let mut migrations = Vec::new();
+        for entry in std::fs::read_dir(dir)? {
+            let entry = entry?;
+            let file_name = entry.file_name();
+            let file_name = file_name.to_string_lossy();
+            if !file_name.ends_with(".sql") {
+                continue;
+            }
+            let sql = std::fs::read_to_string(entry.path())?;
+            match Migration::from_file(&file_name, sql) {
+                Some(migration) => migrations.push(migration),
+                None => {
+                    return Err(AgentDbError::InvalidOperation(format!(
+                        "migration file name does not match V{{version}}__{{description}}.sql: {}",
+                        file_name
+                    )))
+                }
+            }
+        }
+        Ok(Self::new(migrations))
+    }
+
+    async fn ensure_tracking_table<D: AgentDB>(&self, db: &D) -> Result<()> {
+        db.query(
+            "CREATE TABLE IF NOT EXISTS _agentdb_migrations (\
+                version INTEGER PRIMARY KEY, \
+                description TEXT NOT NULL, \
+                checksum BLOB NOT NULL, \
+                applied_at INTEGER NOT NULL\
+            )",
+            Vec::new(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn applied_checksums<D: AgentDB>(&self, db: &D) -> Result<HashMap<i64, [u8; 32]>> {
+        let result = db
+            .query(
+                "SELECT version, checksum FROM _agentdb_migrations",
+                Vec::new(),
+            )
+            .await?;
+        let mut applied = HashMap::new();
+        for row in result.rows {
+            let version = row
+                .get("version")
+                .and_then(|v| v.as_bytes().try_into().ok())
+                .map(i64::from_be_bytes)
+                .ok_or_else(|| AgentDbError::Serialization("malformed migration version".to_string()))?;
+            let checksum: [u8; 32] = row
+                .get("checksum")
+                .and_then(|v| v.as_bytes().try_into().ok())
+                .ok_or_else(|| AgentDbError::Serialization("malformed migration checksum".to_string()))?;
+            applied.insert(version, checksum);
+        }
+        Ok(applied)
+    }
+
+    /// Apply all pending migrations, in version order, inside transactions.
+    ///
+    /// Returns [`AgentDbError::Unsupported`] on backends that don't support
+    /// SQL queries and transactions, and [`AgentDbError::InvalidOperation`]
+    /// if an already-applied migration's checksum no longer matches its
+    /// source (drift).
+    pub async fn run<D: AgentDB>(&self, db: &D) -> Result<()> {
+        if !db.capabilities().supports_sql_queries() || !db.capabilities().supports_transactions() {
+            return Err(AgentDbError::Unsupported(
+                "migrations require SQL queries and transactions".to_string(),
+            ));
+        }
+
+        self.ensure_tracking_table(db).await?;
+        let applied = self.applied_checksums(db).await?;
+
+        for migration in &self.migrations {
+            if let Some(existing_checksum) = applied.get(&migration.version) {
+                if existing_checksum != &migration.checksum {
+                    return Err(AgentDbError::InvalidOperation(format!(
+                        "migration {} has drifted: checksum no longer matches the applied version",
+                        migration.version
+                    )));
+                }
+                continue;
+            }
+
+            let sql = migration.sql.clone();
+            let version = migration.version;
+            let description = migration.description.clone();
+            let checksum = migration.checksum;
+            let applied_at = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+
+            db.transaction(move |tx_db| async move {
+                tx_db.query(&sql, Vec::new()).await?;
+                tx_db
+                    .query(
+                        "INSERT INTO _agentdb_migrations (version, description, checksum, applied_at) \
+                         VALUES (?, ?, ?, ?)",
+                        vec![
+                            Value::from(version.to_be_bytes().to_vec()),
+                            Value::from(description.as_bytes()),
+                            Value::from(checksum.to_vec()),
+                            Value::from(applied_at.to_be_bytes().to_vec()),
+                        ],
+                    )
+                    .await?;
+                Ok(())
+            })
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Report which of this migrator's versions have been applied vs. are pending.
+    pub async fn status<D: AgentDB>(&self, db: &D) -> Result<MigrationStatus> {
+        if !db.capabilities().supports_sql_queries() {
+            return Err(AgentDbError::Unsupported(
+                "migration status requires SQL queries".to_string(),
+            ));
+        }
+
+        self.ensure_tracking_table(db).await?;
+        let applied = self.applied_checksums(db).await?;
+
+        let mut status = MigrationStatus::default();
+        for migration in &self.migrations {
+            if applied.contains_key(&migration.version) {
+                status.applied.push(migration.version);
+            } else {
+                status.pending.push(migration.version);
+            }
+        }
+        Ok(status)
+    }
+}
+
+/// Minimal, dependency-free SHA-256 (FIPS 180-4) used to checksum migration SQL.
+fn sha256(data: &[u8]) -> [u8; 32] {
+    const K: [u32; 64] = [
+        0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+        0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+        0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+        0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+        0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+        0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+        0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+        0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+    ];
+
+    let mut h: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+    ];
+
+    let mut msg = data.to_vec();
+    let bit_len = (data.len() as u64) * 8;
+    msg.push(0x80);
+    while msg.len() % 64 != 56 {
+        msg.push(0);
+    }
+    msg.extend_from_slice(&bit_len.to_be_bytes());
+
+    for chunk in msg.chunks(64) {
+        let mut w = [0u32; 64];
+        for (i, word) in chunk.chunks(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[i - 7])
+                .wrapping_add(s1);
+        }
+
+        let (mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut hh) =
+            (h[0], h[1], h[2], h[3], h[4], h[5], h[6], h[7]);
+
+        for i in 0..64 {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ ((!e) & g);
+            let temp1 = hh
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(K[i])
+                .wrapping_add(w[i]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let temp2 = s0.wrapping_add(maj);
+
+            hh = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(temp1);
+            d = c;
+            c = b;
+            b = a;
+            a = temp1.wrapping_add(temp2);
+        }
+
+        h[0] = h[0].wrapping_add(a);
+        h[1] = h[1].wrapping_add(b);
+        h[2] = h[2].wrapping_add(c);
+        h[3] = h[3].wrapping_add(d);
+        h[4] = h[4].wrapping_add(e);
+        h[5] = h[5].wrapping_add(f);
+        h[6] = h[6].wrapping_add(g);
+        h[7] = h[7].wrapping_add(hh);
+    }
+
+    let mut out = [0u8; 32];
+    for (i, word) in h.iter().enumerate() {
+        out[i * 4..i * 4 + 4].copy_from_slice(&word.to_be_bytes());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn sha256_matches_known_vectors() {
+        assert_eq!(
+            hex(&sha256(b"")),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            hex(&sha256(b"abc")),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn from_file_parses_version_and_description() {
+        let migration = Migration::from_file("V3__add_users_table.sql", "SELECT 1".to_string()).unwrap();
+        assert_eq!(migration.version, 3);
+        assert_eq!(migration.description, "add users table");
+        assert_eq!(migration.sql, "SELECT 1");
+        assert_eq!(migration.checksum, sha256(b"SELECT 1"));
+    }
+
+    #[test]
+    fn from_file_rejects_names_without_version_prefix() {
+        assert!(Migration::from_file("add_users_table.sql", "SELECT 1".to_string()).is_none());
+    }
+
+    #[test]
+    fn from_file_rejects_names_without_separator() {
+        assert!(Migration::from_file("V3-add_users_table.sql", "SELECT 1".to_string()).is_none());
+    }
+}
@@ -0,0 +1,253 @@
+//! Backend-agnostic async connection pool built over the [`AgentDB`] trait.
+//!
+//! [`Pool`] hands out bounded, health-checked connections so agents can share
+//! a fixed set of backend connections instead of opening one per operation.
+//! A background reaper periodically closes connections that have sat idle
+//! past `idle_timeout`.
+
+use crate::{AgentDB, AgentDbError, Result};
+use std::collections::VecDeque;
+use std::future::Future;
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex, Notify};
+use tokio::time::timeout;
+
+type Factory<D> = Box<dyn Fn() -> Pin<Box<dyn Future<Output = Result<D>> + Send>> + Send + Sync>;
+
+struct Idle<D> {
+    conn: D,
+    since: Instant,
+}
+
+struct Shared<D> {
+    factory: Factory<D>,
+    min_idle: usize,
+    idle_timeout: Duration,
+    idle: Mutex<VecDeque<Idle<D>>>,
+    size: Mutex<usize>,
+    max_size: usize,
+    notify: Notify,
+}
+
+/// Builder for a [`Pool`].
+///
+/// Mirrors the bounded-pool builders used by deadpool-style crates: configure
+/// `max_size`, `min_idle`, `acquire_timeout`, and `idle_timeout`, then call
+/// [`build`](PoolBuilder::build) with a connection factory.
+pub struct PoolBuilder {
+    max_size: usize,
+    min_idle: usize,
+    acquire_timeout: Duration,
+    idle_timeout: Duration,
+}
+
+impl PoolBuilder {
+    /// Start a builder with sensible defaults (max_size 10, min_idle 0,
+    /// 30s acquire timeout, 5 minute idle timeout).
+    pub fn new() -> Self {
+        Self {
+            max_size: 10,
+            min_idle: 0,
+            acquire_timeout: Duration::from_secs(30),
+            idle_timeout: Duration::from_secs(5 * 60),
+        }
+    }
+
+    /// Maximum number of connections the pool will ever hold (idle + in use).
+    pub fn max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Minimum number of idle connections the reaper keeps warm.
+    pub fn min_idle(mut self, min_idle: usize) -> Self {
+        self.min_idle = min_idle;
+        self
+    }
+
+    /// How long [`Pool::acquire`] waits for a connection before giving up.
+    pub fn acquire_timeout(mut self, acquire_timeout: Duration) -> Self {
+        self.acquire_timeout = acquire_timeout;
+        self
+    }
+
+    /// How long a connection may sit idle before the reaper closes it.
+    pub fn idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = idle_timeout;
+        self
+    }
+
+    /// Build the pool, spawning its background reaper task.
+    ///
+    /// `factory` creates a fresh connection on demand; it is called whenever
+    /// the pool needs a new connection and none are idle.
+    pub fn build<D, F, Fut>(self, factory: F) -> Pool<D>
+    where
+        D: AgentDB + 'static,
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<D>> + Send + 'static,
+    {
+        let shared = Arc::new(Shared {
+            factory: Box::new(move || Box::pin(factory())),
+            min_idle: self.min_idle,
+            idle_timeout: self.idle_timeout,
+            idle: Mutex::new(VecDeque::new()),
+            size: Mutex::new(0),
+            max_size: self.max_size,
+            notify: Notify::new(),
+        });
+
+        let reaper_shared = Arc::clone(&shared);
+        let reap_every = self.idle_timeout.max(Duration::from_secs(1));
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(reap_every);
+            loop {
+                ticker.tick().await;
+                reap_idle(&reaper_shared).await;
+            }
+        });
+
+        Pool {
+            shared,
+            acquire_timeout: self.acquire_timeout,
+        }
+    }
+}
+
+impl Default for PoolBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+async fn reap_idle<D>(shared: &Arc<Shared<D>>) {
+    let mut idle = shared.idle.lock().await;
+    let mut size = shared.size.lock().await;
+    let mut reaped = 0;
+    while idle.len() > shared.min_idle {
+        let expired = idle
+            .front()
+            .map(|entry| entry.since.elapsed() >= shared.idle_timeout)
+            .unwrap_or(false);
+        if !expired {
+            break;
+        }
+        idle.pop_front();
+        *size -= 1;
+        reaped += 1;
+    }
+    drop(idle);
+    drop(size);
+    // Evicting idle connections frees capacity for a new connection, so wake
+    // anyone blocked in acquire() waiting for a slot.
+    for _ in 0..reaped {
+        shared.notify.notify_one();
+    }
+}
+
+/// A bounded, health-checked pool of [`AgentDB`] connections.
+///
+/// Construct with [`PoolBuilder`]. Acquire connections with [`acquire`](Pool::acquire);
+/// the returned [`PooledConnection`] returns the connection to the pool when dropped.
+pub struct Pool<D: AgentDB + 'static> {
+    shared: Arc<Shared<D>>,
+    acquire_timeout: Duration,
+}
+
+impl<D: AgentDB + 'static> Pool<D> {
+    /// Start building a pool.
+    pub fn builder() -> PoolBuilder {
+        PoolBuilder::new()
+    }
+
+    /// Acquire a connection, waiting up to `acquire_timeout` for one to
+    /// become available.
+    ///
+    /// Idle connections are health-checked with [`AgentDB::ping`] before
+    /// being handed out; connections that fail the ping are discarded and a
+    /// replacement is created via the pool's factory.
+    pub async fn acquire(&self) -> Result<PooledConnection<D>> {
+        timeout(self.acquire_timeout, self.acquire_inner())
+            .await
+            .map_err(|_| AgentDbError::Connection("timed out waiting for a pooled connection".to_string()))?
+    }
+
+    async fn acquire_inner(&self) -> Result<PooledConnection<D>> {
+        loop {
+            if let Some(entry) = self.shared.idle.lock().await.pop_front() {
+                if entry.conn.ping().await.is_ok() {
+                    return Ok(PooledConnection {
+                        conn: Some(entry.conn),
+                        shared: Arc::clone(&self.shared),
+                    });
+                }
+                // Failed the health check: drop it and shrink the accounted size.
+                let mut size = self.shared.size.lock().await;
+                *size = size.saturating_sub(1);
+                drop(size);
+                // Freed a slot below max_size; wake anyone else waiting to acquire.
+                self.shared.notify.notify_one();
+                continue;
+            }
+
+            let mut size = self.shared.size.lock().await;
+            if *size < self.shared.max_size {
+                *size += 1;
+                drop(size);
+                return match (self.shared.factory)().await {
+                    Ok(conn) => Ok(PooledConnection {
+                        conn: Some(conn),
+                        shared: Arc::clone(&self.shared),
+                    }),
+                    Err(err) => {
+                        let mut size = self.shared.size.lock().await;
+                        *size = size.saturating_sub(1);
+                        Err(err)
+                    }
+                };
+            }
+            drop(size);
+            self.shared.notify.notified().await;
+        }
+    }
+}
+
+/// An RAII-guarded connection checked out from a [`Pool`].
+///
+/// Returns the connection to the pool's idle queue when dropped.
+pub struct PooledConnection<D: AgentDB + 'static> {
+    conn: Option<D>,
+    shared: Arc<Shared<D>>,
+}
+
+impl<D: AgentDB + 'static> Deref for PooledConnection<D> {
+    type Target = D;
+
+    fn deref(&self) -> &D {
+        self.conn.as_ref().expect("connection taken before drop")
+    }
+}
+
+impl<D: AgentDB + 'static> DerefMut for PooledConnection<D> {
+    fn deref_mut(&mut self) -> &mut D {
+        self.conn.as_mut().expect("connection taken before drop")
+    }
+}
+
+impl<D: AgentDB + 'static> Drop for PooledConnection<D> {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            let shared = Arc::clone(&self.shared);
+            tokio::spawn(async move {
+                shared.idle.lock().await.push_back(Idle {
+                    conn,
+                    since: Instant::now(),
+                });
+                shared.notify.notify_one();
+            });
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! Retention/pruning modes for log-structured and versioned keyspaces.
+//!
+//! Agents often append versioned memory/event keys such as
+//! `agent/{id}/events/{seq}` and need bounded storage for them.
+//! [`PruningMode`] borrows the archive-all vs. keep-N distinction from
+//! block database pruning modes; [`AgentDB::prune`](crate::AgentDB::prune)
+//! applies it to all keys under a prefix.
+
+/// How much history to retain under a pruned prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PruningMode {
+    /// Keep everything; `prune` is a no-op.
+    ArchiveAll,
+    /// Keep only the `n` highest-numbered entries per prefix group.
+    KeepLast(usize),
+    /// Keep entries whose TTL subsystem deadline is still within `duration`
+    /// of now; prune anything that expired earlier than that.
+    KeepSince(std::time::Duration),
+}
+
+/// Given the keys returned by a prefix scan, return the subset to delete to
+/// satisfy [`PruningMode::KeepLast`].
+///
+/// Keys are expected to end in a numeric sequence segment (as in
+/// `agent/{id}/events/{seq}`); keys that don't parse as such are left alone
+/// since they aren't part of any numbered series being pruned. Ranking is
+/// done per series — the part of the key before the trailing `/{seq}` — so
+/// `keep` highest-numbered entries are retained independently for each
+/// `agent/{id}/events/` group rather than across the whole scanned prefix.
+pub(crate) fn select_for_keep_last(keys: Vec<String>, keep: usize) -> Vec<String> {
+    use std::collections::HashMap;
+
+    let mut groups: HashMap<String, Vec<(u64, String)>> = HashMap::new();
+    for key in keys {
+        let Some((series, seq)) = key.rsplit_once('/') else {
+            continue;
+        };
+        let Ok(seq) = seq.parse::<u64>() else {
+            continue;
+        };
+        groups.entry(series.to_string()).or_default().push((seq, key));
+    }
+
+    let mut to_delete = Vec::new();
+    for (_, mut entries) in groups {
+        entries.sort_by_key(|(seq, _)| std::cmp::Reverse(*seq));
+        to_delete.extend(entries.into_iter().skip(keep).map(|(_, key)| key));
+    }
+    to_delete
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_highest_numbered_entries_per_series() {
+        let keys = vec![
+            "agent/1/events/1".to_string(),
+            "agent/1/events/2".to_string(),
+            "agent/1/events/3".to_string(),
+        ];
+        let mut to_delete = select_for_keep_last(keys, 2);
+        to_delete.sort();
+        assert_eq!(to_delete, vec!["agent/1/events/1".to_string()]);
+    }
+
+    #[test]
+    fn ranks_each_series_independently() {
+        let keys = vec![
+            "agent/1/events/1".to_string(),
+            "agent/1/events/2".to_string(),
+            "agent/2/events/1".to_string(),
+            "agent/2/events/2".to_string(),
+            "agent/2/events/3".to_string(),
+        ];
+        let mut to_delete = select_for_keep_last(keys, 1);
+        to_delete.sort();
+        assert_eq!(
+            to_delete,
+            vec![
+                "agent/1/events/1".to_string(),
+                "agent/2/events/1".to_string(),
+                "agent/2/events/2".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn leaves_keys_without_a_numeric_sequence_segment_alone() {
+        let keys = vec!["agent/1/profile".to_string()];
+        assert_eq!(select_for_keep_last(keys, 0), Vec::<String>::new());
+    }
+}
@@ -0,0 +1,146 @@
+//! Prepared-statement cache for SQL-family backends.
+//!
+//! [`AgentDB::prepare`] lets a backend parse a SQL string once and reuse the
+//! resulting [`Statement`] across calls. [`StatementCache`] is a bounded LRU
+//! keyed by the query text, mirroring the statement caches Diesel and sqlx
+//! keep per-connection, so `query()` can consult it before reparsing.
+//!
+//! This is gated behind [`Capabilities::supports_prepared_statements`](crate::Capabilities::supports_prepared_statements):
+//! KV/graph backends that can't prepare statements simply fall through to
+//! issuing `query()` directly.
+
+use crate::{QueryResult, Result, Value};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// A prepared statement: a backend handle plus column metadata, ready to be
+/// executed or queried with fresh parameters.
+#[async_trait]
+pub trait Statement: Send + Sync {
+    /// Column names produced by this statement, in order.
+    fn columns(&self) -> &[String];
+
+    /// Execute the statement for its side effects, returning rows affected.
+    async fn execute(&self, params: Vec<Value>) -> Result<QueryResult>;
+
+    /// Execute the statement and return its rows.
+    async fn query(&self, params: Vec<Value>) -> Result<QueryResult>;
+}
+
+/// A bounded least-recently-used cache of prepared statements, keyed by the
+/// exact SQL text.
+///
+/// Backends embed one of these per connection and consult it from their
+/// `query()` implementation: look up the SQL text, call
+/// [`AgentDB::prepare`](crate::AgentDB::prepare) and [`insert`](StatementCache::insert) on
+/// miss, then run the cached [`Statement`].
+pub struct StatementCache {
+    capacity: usize,
+    // Front = most recently used.
+    entries: Mutex<Vec<(String, Arc<dyn Statement>)>>,
+}
+
+impl StatementCache {
+    /// Create a cache holding at most `capacity` prepared statements.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Look up a cached statement for `sql`, marking it most-recently-used on hit.
+    pub async fn get(&self, sql: &str) -> Option<Arc<dyn Statement>> {
+        let mut entries = self.entries.lock().await;
+        let pos = entries.iter().position(|(key, _)| key == sql)?;
+        let (key, stmt) = entries.remove(pos);
+        entries.insert(0, (key, Arc::clone(&stmt)));
+        Some(stmt)
+    }
+
+    /// Insert a freshly prepared statement, evicting the least-recently-used
+    /// entry if the cache is at capacity.
+    pub async fn insert(&self, sql: String, statement: Arc<dyn Statement>) {
+        let mut entries = self.entries.lock().await;
+        entries.retain(|(key, _)| key != &sql);
+        entries.insert(0, (sql, statement));
+        while entries.len() > self.capacity {
+            entries.pop();
+        }
+    }
+
+    /// Number of statements currently cached.
+    pub async fn len(&self) -> usize {
+        self.entries.lock().await.len()
+    }
+
+    /// Whether the cache currently holds no statements.
+    pub async fn is_empty(&self) -> bool {
+        self.len().await == 0
+    }
+}
+
+/// Column metadata for a [`Statement`], keyed by position.
+#[derive(Debug, Clone, Default)]
+pub struct ColumnInfo {
+    /// Column names in result order.
+    pub names: Vec<String>,
+    /// Declared SQL types, if the backend exposes them.
+    pub types: HashMap<String, String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct StubStatement;
+
+    #[async_trait]
+    impl Statement for StubStatement {
+        fn columns(&self) -> &[String] {
+            &[]
+        }
+
+        async fn execute(&self, _params: Vec<Value>) -> Result<QueryResult> {
+            unimplemented!()
+        }
+
+        async fn query(&self, _params: Vec<Value>) -> Result<QueryResult> {
+            unimplemented!()
+        }
+    }
+
+    fn stub() -> Arc<dyn Statement> {
+        Arc::new(StubStatement)
+    }
+
+    #[tokio::test]
+    async fn evicts_least_recently_used_on_insert_over_capacity() {
+        let cache = StatementCache::new(2);
+        cache.insert("a".to_string(), stub()).await;
+        cache.insert("b".to_string(), stub()).await;
+        cache.insert("c".to_string(), stub()).await;
+
+        assert!(cache.get("a").await.is_none());
+        assert!(cache.get("b").await.is_some());
+        assert!(cache.get("c").await.is_some());
+        assert_eq!(cache.len().await, 2);
+    }
+
+    #[tokio::test]
+    async fn get_refreshes_recency_so_it_survives_eviction() {
+        let cache = StatementCache::new(2);
+        cache.insert("a".to_string(), stub()).await;
+        cache.insert("b".to_string(), stub()).await;
+
+        // Touch "a" so "b" becomes the least-recently-used entry.
+        assert!(cache.get("a").await.is_some());
+        cache.insert("c".to_string(), stub()).await;
+
+        assert!(cache.get("a").await.is_some());
+        assert!(cache.get("b").await.is_none());
+        assert!(cache.get("c").await.is_some());
+    }
+}
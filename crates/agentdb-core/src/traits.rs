@@ -0,0 +1,508 @@
+//! Core traits for AgentDB
+
+use crate::prune::{select_for_keep_last, PruningMode};
+use crate::statement::Statement;
+use crate::transaction::{release_savepoint_sql, rollback_to_savepoint_sql, savepoint_sql, TransactionDepth};
+use crate::{AgentDbError, BackendFamily, Result, Value};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::future::Future;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Create the `_agentdb_ttl` bookkeeping table if it doesn't already exist.
+///
+/// Called lazily by the default TTL emulation in [`AgentDB::expire`],
+/// [`AgentDB::ttl`], [`AgentDB::sweep_expired`], and the `KeepSince` arm of
+/// [`AgentDB::prune`], mirroring [`Migrator`](crate::migrate::Migrator)'s own
+/// `ensure_tracking_table` bootstrap.
+async fn ensure_ttl_table<D: AgentDB + ?Sized>(db: &D) -> Result<()> {
+    db.query(
+        "CREATE TABLE IF NOT EXISTS _agentdb_ttl (\
+            key BLOB PRIMARY KEY, \
+            expires_at BLOB NOT NULL\
+        )",
+        Vec::new(),
+    )
+    .await?;
+    Ok(())
+}
+
+/// Main trait for database operations
+///
+/// Provides a unified interface for CRUD operations, transactions, queries, and scans
+/// across different backend families (SQL, KV, Graph).
+#[async_trait]
+pub trait AgentDB: Send + Sync {
+    /// Get backend family type
+    fn family(&self) -> BackendFamily;
+
+    /// Get backend capabilities
+    fn capabilities(&self) -> &dyn Capabilities;
+
+    /// Store a key-value pair
+    async fn put(&self, key: &str, value: Value) -> Result<()>;
+
+    /// Retrieve a value by key
+    async fn get(&self, key: &str) -> Result<Option<Value>>;
+
+    /// Delete a key
+    async fn delete(&self, key: &str) -> Result<()>;
+
+    /// Check if a key exists
+    async fn exists(&self, key: &str) -> Result<bool>;
+
+    /// Execute a query (backend-specific)
+    async fn query(&self, query: &str, params: Vec<Value>) -> Result<QueryResult>;
+
+    /// Scan keys with a prefix
+    async fn scan(&self, prefix: &str) -> Result<ScanResult>;
+
+    /// Begin a transaction
+    async fn begin(&self) -> Result<Box<dyn Transaction>>;
+
+    /// Close the database connection
+    async fn close(&self) -> Result<()>;
+
+    /// Cheaply verify the connection is still alive.
+    ///
+    /// Used by [`Pool`](crate::pool::Pool) to health-check idle connections
+    /// before handing them out. The default implementation does an `exists`
+    /// check on a sentinel key; backends with a cheaper native ping (e.g. a
+    /// protocol-level `PING` command) should override this.
+    async fn ping(&self) -> Result<()> {
+        self.exists("__agentdb_ping__").await.map(|_| ())
+    }
+
+    /// Parse `sql` into a reusable [`Statement`].
+    ///
+    /// Gated on [`Capabilities::supports_prepared_statements`]; backends that
+    /// can't prepare statements (KV, graph) should leave the default
+    /// implementation in place, which returns [`AgentDbError::Unsupported`].
+    /// SQL backends typically consult a [`StatementCache`](crate::statement::StatementCache)
+    /// keyed by `sql` before calling into this method.
+    async fn prepare(&self, _sql: &str) -> Result<Box<dyn Statement>> {
+        Err(AgentDbError::Unsupported("prepared statements".to_string()))
+    }
+
+    /// Store `value` at `key` with an expiry of `ttl` from now.
+    ///
+    /// Gated on [`Capabilities::supports_ttl`]. The default implementation
+    /// is a `put` followed by [`expire`](AgentDB::expire); native-TTL
+    /// backends (Redis/KeyDB-style KV) should override both for a single
+    /// round trip.
+    async fn put_with_ttl(&self, key: &str, value: Value, ttl: Duration) -> Result<()> {
+        if !self.capabilities().supports_ttl() {
+            return Err(AgentDbError::Unsupported("ttl".to_string()));
+        }
+        self.put(key, value).await?;
+        self.expire(key, ttl).await
+    }
+
+    /// Set or refresh `key`'s expiry to `ttl` from now.
+    ///
+    /// Native-TTL backends should map this to their own expire command. The
+    /// default emulation is for SQL backends: it records the deadline in an
+    /// `_agentdb_ttl(key, expires_at)` bookkeeping table. Backends relying on
+    /// this emulation must have `get`/`exists` treat a key whose deadline has
+    /// passed as absent, and should run [`sweep_expired`](AgentDB::sweep_expired)
+    /// periodically to reclaim the rows.
+    async fn expire(&self, key: &str, ttl: Duration) -> Result<()> {
+        if !self.capabilities().supports_ttl() {
+            return Err(AgentDbError::Unsupported("ttl".to_string()));
+        }
+        ensure_ttl_table(self).await?;
+        let deadline = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .saturating_add(ttl)
+            .as_secs();
+        self.query(
+            "INSERT INTO _agentdb_ttl (key, expires_at) VALUES (?, ?) \
+             ON CONFLICT(key) DO UPDATE SET expires_at = excluded.expires_at",
+            vec![Value::from(key.as_bytes()), Value::from(deadline.to_be_bytes().to_vec())],
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Look up the remaining time-to-live for `key`, if any.
+    ///
+    /// Returns `Ok(None)` if the key has no expiry set. See [`expire`](AgentDB::expire)
+    /// for the default SQL emulation this reads from.
+    async fn ttl(&self, key: &str) -> Result<Option<Duration>> {
+        if !self.capabilities().supports_ttl() {
+            return Err(AgentDbError::Unsupported("ttl".to_string()));
+        }
+        ensure_ttl_table(self).await?;
+        let result = self
+            .query(
+                "SELECT expires_at FROM _agentdb_ttl WHERE key = ?",
+                vec![Value::from(key.as_bytes())],
+            )
+            .await?;
+        let Some(row) = result.rows.into_iter().next() else {
+            return Ok(None);
+        };
+        let expires_at = row
+            .get("expires_at")
+            .and_then(|v| v.as_bytes().try_into().ok())
+            .map(u64::from_be_bytes)
+            .ok_or_else(|| AgentDbError::Serialization("malformed ttl deadline".to_string()))?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        if expires_at <= now {
+            // Deadline already passed: treat as absent rather than reporting
+            // a zero-duration TTL, matching get/exists' view of expired keys.
+            return Ok(None);
+        }
+        Ok(Some(Duration::from_secs(expires_at - now)))
+    }
+
+    /// Delete all keys whose TTL deadline has passed, returning how many were removed.
+    ///
+    /// Native-TTL backends generally expire keys themselves and can leave
+    /// this as a no-op; it exists for the default SQL emulation in
+    /// [`expire`](AgentDB::expire).
+    async fn sweep_expired(&self) -> Result<usize> {
+        if !self.capabilities().supports_ttl() {
+            return Err(AgentDbError::Unsupported("ttl".to_string()));
+        }
+        ensure_ttl_table(self).await?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let result = self
+            .query(
+                "DELETE FROM _agentdb_ttl WHERE expires_at <= ?",
+                vec![Value::from(now.to_be_bytes().to_vec())],
+            )
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    /// The transaction nesting depth counter for this connection.
+    ///
+    /// Implementors own a [`TransactionDepth`] per connection and return a
+    /// reference to it here so [`transaction`](AgentDB::transaction) can tell
+    /// whether to open a new transaction or a nested savepoint.
+    fn transaction_depth(&self) -> &TransactionDepth;
+
+    /// Run `f` inside a transaction scope, committing on `Ok(_)` and rolling
+    /// back on `Err(_)`.
+    ///
+    /// Scopes nest: the outermost call (depth 0) goes through the existing
+    /// [`begin`](AgentDB::begin) / [`Transaction`] flow, so it works for any
+    /// backend that implements `begin`, SQL-shaped or not. Calling
+    /// `transaction` again while already inside one instead opens a
+    /// `SAVEPOINT` via `query()`, and unwinds it with `RELEASE SAVEPOINT` /
+    /// `ROLLBACK TO SAVEPOINT` on exit, so callers can compose transactional
+    /// operations without tracking nesting themselves.
+    ///
+    /// KV and graph backends generally can't execute nested savepoints, so
+    /// entering at depth > 0 on a backend without [`Capabilities::supports_sql_queries`]
+    /// returns [`AgentDbError::Unsupported`]; entering at depth 0 is instead
+    /// gated on [`Capabilities::supports_transactions`], independent of
+    /// whether the backend speaks SQL.
+    async fn transaction<'a, F, Fut, T>(&'a self, f: F) -> Result<T>
+    where
+        Self: Sized,
+        F: FnOnce(&'a Self) -> Fut + Send,
+        Fut: Future<Output = Result<T>> + Send + 'a,
+        T: Send,
+    {
+        let depth = self.transaction_depth().enter();
+
+        if depth == 0 {
+            if !self.capabilities().supports_transactions() {
+                self.transaction_depth().exit();
+                return Err(AgentDbError::Unsupported("transactions".to_string()));
+            }
+
+            let tx = match self.begin().await {
+                Ok(tx) => tx,
+                Err(err) => {
+                    self.transaction_depth().exit();
+                    return Err(err);
+                }
+            };
+
+            return match f(self).await {
+                Ok(value) => {
+                    let result = tx.commit().await;
+                    self.transaction_depth().exit();
+                    result.map(|_| value)
+                }
+                Err(err) => {
+                    let _ = tx.rollback().await;
+                    self.transaction_depth().exit();
+                    Err(err)
+                }
+            };
+        }
+
+        if !self.capabilities().supports_sql_queries() {
+            self.transaction_depth().exit();
+            return Err(AgentDbError::Unsupported(
+                "nested transactions (savepoints)".to_string(),
+            ));
+        }
+
+        if let Err(err) = self.query(&savepoint_sql(depth), Vec::new()).await {
+            self.transaction_depth().exit();
+            return Err(err);
+        }
+
+        match f(self).await {
+            Ok(value) => {
+                let result = self.query(&release_savepoint_sql(depth), Vec::new()).await;
+                self.transaction_depth().exit();
+                result.map(|_| value)
+            }
+            Err(err) => {
+                let _ = self.query(&rollback_to_savepoint_sql(depth), Vec::new()).await;
+                let _ = self.query(&release_savepoint_sql(depth), Vec::new()).await;
+                self.transaction_depth().exit();
+                Err(err)
+            }
+        }
+    }
+
+    /// Trim historical entries under `prefix` according to `mode`, returning
+    /// how many keys were removed.
+    ///
+    /// Gated on [`Capabilities::supports_pruning`]. `KeepLast` is implemented
+    /// generically via [`scan`](AgentDB::scan) + [`delete`](AgentDB::delete);
+    /// `KeepSince` reads the `_agentdb_ttl` bookkeeping table from the TTL
+    /// subsystem (see [`expire`](AgentDB::expire)), so it requires
+    /// [`Capabilities::supports_ttl`] in addition to `supports_pruning`.
+    async fn prune(&self, prefix: &str, mode: PruningMode) -> Result<usize> {
+        if !self.capabilities().supports_pruning() {
+            return Err(AgentDbError::Unsupported("pruning".to_string()));
+        }
+
+        match mode {
+            PruningMode::ArchiveAll => Ok(0),
+            PruningMode::KeepLast(keep) => {
+                let scanned = self.scan(prefix).await?;
+                let to_delete = select_for_keep_last(scanned.keys, keep);
+                for key in &to_delete {
+                    self.delete(key).await?;
+                }
+                Ok(to_delete.len())
+            }
+            PruningMode::KeepSince(duration) => {
+                if !self.capabilities().supports_ttl() {
+                    return Err(AgentDbError::Unsupported(
+                        "KeepSince pruning requires TTL support".to_string(),
+                    ));
+                }
+                ensure_ttl_table(self).await?;
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_secs();
+                let cutoff = now.saturating_sub(duration.as_secs());
+                let result = self
+                    .query(
+                        "SELECT key FROM _agentdb_ttl WHERE key LIKE ? AND expires_at <= ?",
+                        vec![
+                            Value::from(format!("{}%", prefix).into_bytes()),
+                            Value::from(cutoff.to_be_bytes().to_vec()),
+                        ],
+                    )
+                    .await?;
+                let mut removed = 0;
+                for row in result.rows {
+                    let Some(key) = row.get("key") else { continue };
+                    let key = String::from_utf8_lossy(key.as_bytes()).into_owned();
+                    self.delete(&key).await?;
+                    removed += 1;
+                }
+                Ok(removed)
+            }
+        }
+    }
+}
+
+/// Backend capability descriptor
+pub trait Capabilities: Send + Sync {
+    /// Does this backend support ACID transactions?
+    fn supports_transactions(&self) -> bool;
+
+    /// Does this backend support directory-like hierarchies?
+    fn supports_directories(&self) -> bool;
+
+    /// Does this backend support graph traversals?
+    fn supports_graph_queries(&self) -> bool;
+
+    /// Does this backend support structured SQL queries?
+    fn supports_sql_queries(&self) -> bool;
+
+    /// Does this backend support secondary indexes?
+    fn supports_indexes(&self) -> bool;
+
+    /// Does this backend support TTL (time-to-live) for keys?
+    fn supports_ttl(&self) -> bool;
+
+    /// Does this backend support preparing SQL statements ahead of execution?
+    fn supports_prepared_statements(&self) -> bool;
+
+    /// Does this backend support atomically pruning historical keys ([`crate::prune::PruningMode`])?
+    fn supports_pruning(&self) -> bool;
+
+    /// Maximum key size in bytes (None = unlimited)
+    fn max_key_size(&self) -> Option<usize>;
+
+    /// Maximum value size in bytes (None = unlimited)
+    fn max_value_size(&self) -> Option<usize>;
+}
+
+/// Transaction interface
+#[async_trait]
+pub trait Transaction: Send + Sync {
+    /// Commit the transaction
+    async fn commit(self: Box<Self>) -> Result<()>;
+
+    /// Rollback the transaction
+    async fn rollback(self: Box<Self>) -> Result<()>;
+}
+
+/// Result of a query operation
+#[derive(Debug, Clone)]
+pub struct QueryResult {
+    /// Rows returned by the query
+    pub rows: Vec<Row>,
+
+    /// Number of rows affected (for INSERT/UPDATE/DELETE)
+    pub rows_affected: usize,
+}
+
+impl QueryResult {
+    /// Create a new query result
+    pub fn new(rows: Vec<Row>, rows_affected: usize) -> Self {
+        Self {
+            rows,
+            rows_affected,
+        }
+    }
+
+    /// Create an empty result
+    pub fn empty() -> Self {
+        Self {
+            rows: Vec::new(),
+            rows_affected: 0,
+        }
+    }
+}
+
+/// A single row from a query result
+#[derive(Debug, Clone)]
+pub struct Row {
+    /// Column name to value mapping
+    pub columns: HashMap<String, Value>,
+}
+
+impl Row {
+    /// Create a new row
+    pub fn new() -> Self {
+        Self {
+            columns: HashMap::new(),
+        }
+    }
+
+    /// Add a column to the row
+    pub fn with_column(mut self, name: impl Into<String>, value: Value) -> Self {
+        self.columns.insert(name.into(), value);
+        self
+    }
+
+    /// Get a column value
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.columns.get(name)
+    }
+}
+
+impl Default for Row {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Result of a scan operation
+#[derive(Debug, Clone)]
+pub struct ScanResult {
+    /// Keys matching the prefix
+    pub keys: Vec<String>,
+}
+
+impl ScanResult {
+    /// Create a new scan result
+    pub fn new(keys: Vec<String>) -> Self {
+        Self { keys }
+    }
+
+    /// Create an empty scan result
+    pub fn empty() -> Self {
+        Self { keys: Vec::new() }
+    }
+}
+
+/// Default capabilities implementation
+#[derive(Debug, Clone, Default)]
+pub struct DefaultCapabilities {
+    pub transactions: bool,
+    pub directories: bool,
+    pub graph_queries: bool,
+    pub sql_queries: bool,
+    pub indexes: bool,
+    pub ttl: bool,
+    pub prepared_statements: bool,
+    pub pruning: bool,
+    pub max_key_size: Option<usize>,
+    pub max_value_size: Option<usize>,
+}
+
+impl Capabilities for DefaultCapabilities {
+    fn supports_transactions(&self) -> bool {
+        self.transactions
+    }
+
+    fn supports_directories(&self) -> bool {
+        self.directories
+    }
+
+    fn supports_graph_queries(&self) -> bool {
+        self.graph_queries
+    }
+
+    fn supports_sql_queries(&self) -> bool {
+        self.sql_queries
+    }
+
+    fn supports_indexes(&self) -> bool {
+        self.indexes
+    }
+
+    fn supports_ttl(&self) -> bool {
+        self.ttl
+    }
+
+    fn supports_prepared_statements(&self) -> bool {
+        self.prepared_statements
+    }
+
+    fn supports_pruning(&self) -> bool {
+        self.pruning
+    }
+
+    fn max_key_size(&self) -> Option<usize> {
+        self.max_key_size
+    }
+
+    fn max_value_size(&self) -> Option<usize> {
+        self.max_value_size
+    }
+}
@@ -0,0 +1,53 @@
+//! Nested transaction support built on top of the flat [`Transaction`](crate::Transaction) trait.
+//!
+//! [`AgentDB::transaction`] gives callers a composable, reentrant transaction
+//! scope: the outermost entry goes through the existing [`begin`](crate::AgentDB::begin) /
+//! [`Transaction`](crate::Transaction) flow, and entering while already inside
+//! one opens a `SAVEPOINT` through `query()` instead, so nested calls compose
+//! without the caller having to track depth itself.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Tracks the transaction nesting depth for a single connection.
+///
+/// A depth of `0` means no transaction is open. Backends that implement
+/// [`AgentDB`](crate::AgentDB) own one of these per connection and hand out a
+/// reference via [`AgentDB::transaction_depth`](crate::AgentDB::transaction_depth).
+#[derive(Debug, Default)]
+pub struct TransactionDepth(AtomicUsize);
+
+impl TransactionDepth {
+    /// Create a new depth counter starting at 0.
+    pub fn new() -> Self {
+        Self(AtomicUsize::new(0))
+    }
+
+    /// The current nesting depth.
+    pub fn current(&self) -> usize {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    /// Enter one level deeper, returning the depth *before* entering.
+    pub(crate) fn enter(&self) -> usize {
+        self.0.fetch_add(1, Ordering::SeqCst)
+    }
+
+    /// Leave the current level.
+    pub(crate) fn exit(&self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// The SQL issued by [`AgentDB::transaction`](crate::AgentDB::transaction) to enter, release, or
+/// unwind a *nested* savepoint at `depth` (always > 0 — depth 0 goes through `begin()`/`Transaction`).
+pub(crate) fn savepoint_sql(depth: usize) -> String {
+    format!("SAVEPOINT agentdb_sp_{}", depth)
+}
+
+pub(crate) fn release_savepoint_sql(depth: usize) -> String {
+    format!("RELEASE SAVEPOINT agentdb_sp_{}", depth)
+}
+
+pub(crate) fn rollback_to_savepoint_sql(depth: usize) -> String {
+    format!("ROLLBACK TO SAVEPOINT agentdb_sp_{}", depth)
+}
@@ -0,0 +1,17 @@
+//! Graph [`AgentDB`](agentdb_core::AgentDB) driver scaffold.
+//!
+//! This crate exists as the seam the graph family (Neo4j, Dgraph,
+//! TigerGraph) will be implemented in once pulled behind the
+//! `agentdb-graph` Cargo feature; this repository never had a concrete
+//! graph backend to move here, so [`connect`] honestly reports that no
+//! driver is implemented yet rather than faking one.
+
+use agentdb_core::{AgentDB, AgentDbError, Result};
+
+/// Always returns [`AgentDbError::Unsupported`]: no concrete graph backend
+/// has been implemented in this crate yet.
+pub async fn connect(_url: &str) -> Result<Box<dyn AgentDB>> {
+    Err(AgentDbError::Unsupported(
+        "agentdb-graph has no concrete driver implementation yet".to_string(),
+    ))
+}
@@ -0,0 +1,117 @@
+//! In-process key-value [`AgentDB`] driver.
+//!
+//! This is the first concrete backend pulled out from behind the
+//! `agentdb-kv` Cargo feature (see `agentdb::connect`). It's intentionally
+//! simple — an in-memory `HashMap` guarded by a `tokio::sync::Mutex`, with no
+//! transactions, SQL, or prepared statements — but it's a real,
+//! separately-compilable driver crate rather than a dispatch stub.
+
+use agentdb_core::{
+    AgentDB, AgentDbError, BackendFamily, Capabilities, DefaultCapabilities, QueryResult, Result,
+    ScanResult, Transaction, TransactionDepth, Value,
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use tokio::sync::Mutex;
+
+/// An in-process, non-persistent key-value store.
+pub struct MemoryKv {
+    data: Mutex<HashMap<String, Vec<u8>>>,
+    capabilities: DefaultCapabilities,
+    transaction_depth: TransactionDepth,
+}
+
+impl MemoryKv {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self {
+            data: Mutex::new(HashMap::new()),
+            capabilities: DefaultCapabilities::default(),
+            transaction_depth: TransactionDepth::new(),
+        }
+    }
+}
+
+impl Default for MemoryKv {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl AgentDB for MemoryKv {
+    fn family(&self) -> BackendFamily {
+        BackendFamily::Kv
+    }
+
+    fn capabilities(&self) -> &dyn Capabilities {
+        &self.capabilities
+    }
+
+    async fn put(&self, key: &str, value: Value) -> Result<()> {
+        self.data.lock().await.insert(key.to_string(), value.into_bytes());
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Value>> {
+        Ok(self.data.lock().await.get(key).map(|bytes| Value::new(bytes.clone())))
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.data.lock().await.remove(key);
+        Ok(())
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool> {
+        Ok(self.data.lock().await.contains_key(key))
+    }
+
+    async fn query(&self, _query: &str, _params: Vec<Value>) -> Result<QueryResult> {
+        Err(AgentDbError::Unsupported(
+            "agentdb-kv does not support SQL queries".to_string(),
+        ))
+    }
+
+    async fn scan(&self, prefix: &str) -> Result<ScanResult> {
+        let keys = self
+            .data
+            .lock()
+            .await
+            .keys()
+            .filter(|key| key.starts_with(prefix))
+            .cloned()
+            .collect();
+        Ok(ScanResult::new(keys))
+    }
+
+    async fn begin(&self) -> Result<Box<dyn Transaction>> {
+        Err(AgentDbError::Unsupported(
+            "agentdb-kv does not support transactions".to_string(),
+        ))
+    }
+
+    async fn close(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn transaction_depth(&self) -> &TransactionDepth {
+        &self.transaction_depth
+    }
+}
+
+/// Connect to the in-process KV store.
+///
+/// Accepts `mem://` for a plain in-memory store, and `mem+tls://` to
+/// exercise the TLS feature gate: since this driver has no network to
+/// secure, `mem+tls://` only validates that a TLS feature is compiled in and
+/// otherwise behaves identically to `mem://`.
+pub async fn connect(url: &str) -> Result<Box<dyn AgentDB>> {
+    let requires_tls = url.starts_with("mem+tls:");
+    let tls_available = cfg!(feature = "tls-rustls") || cfg!(feature = "tls-native-tls");
+    if requires_tls && !tls_available {
+        return Err(AgentDbError::Unsupported(
+            "mem+tls: requires the tls-rustls or tls-native-tls feature".to_string(),
+        ));
+    }
+    Ok(Box::new(MemoryKv::new()))
+}
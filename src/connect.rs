@@ -0,0 +1,75 @@
+//! Backend registry and driver feature gates.
+//!
+//! This module dispatches on a connection URL's scheme to whichever
+//! feature-gated driver crate is compiled in: `agentdb-sql`, `agentdb-kv`,
+//! or `agentdb-graph`, each a separately-compilable crate depending only on
+//! the driver-free `agentdb-core`, mirroring sqlx's core/driver split. The
+//! async runtime (`runtime-tokio` / `runtime-async-std`) and TLS
+//! (`tls-rustls` / `tls-native-tls` / `tls-none`) are Cargo features forwarded
+//! to whichever driver crates are enabled; see the workspace `Cargo.toml`.
+//!
+//! `agentdb-kv` ships a real in-process backend ([`agentdb_kv::MemoryKv`]).
+//! `agentdb-sql` and `agentdb-graph` are scaffolds whose `connect` always
+//! returns [`AgentDbError::Unsupported`] — this repository never had
+//! concrete SQL/graph backends to move into them.
+
+use crate::{AgentDB, AgentDbError, Result};
+
+/// Connect to a backend by URL, dispatching on its scheme to whichever
+/// driver feature is compiled in.
+///
+/// The scheme is everything before the first `:`, matching both
+/// authority-style DSNs (`postgres://user@host/db`) and sqlx's own
+/// authority-less ones (`sqlite::memory:`).
+///
+/// Returns [`AgentDbError::Unsupported`] for schemes whose driver feature
+/// isn't enabled, or whose driver crate doesn't implement a backend yet.
+pub async fn connect(url: &str) -> Result<Box<dyn AgentDB>> {
+    let scheme = url.split_once(':').map(|(scheme, _)| scheme).unwrap_or(url);
+
+    match scheme {
+        "postgres" | "mysql" | "sqlite" => connect_sql(url).await,
+        "redis" | "keydb" | "fdb" | "mem" | "mem+tls" => connect_kv(url).await,
+        "neo4j" | "dgraph" => connect_graph(url).await,
+        other => Err(AgentDbError::Unsupported(format!(
+            "unknown backend scheme: {}",
+            other
+        ))),
+    }
+}
+
+#[cfg(feature = "agentdb-sql")]
+async fn connect_sql(url: &str) -> Result<Box<dyn AgentDB>> {
+    agentdb_sql::connect(url).await
+}
+
+#[cfg(not(feature = "agentdb-sql"))]
+async fn connect_sql(_url: &str) -> Result<Box<dyn AgentDB>> {
+    Err(AgentDbError::Unsupported(
+        "agentdb-sql driver feature not enabled".to_string(),
+    ))
+}
+
+#[cfg(feature = "agentdb-kv")]
+async fn connect_kv(url: &str) -> Result<Box<dyn AgentDB>> {
+    agentdb_kv::connect(url).await
+}
+
+#[cfg(not(feature = "agentdb-kv"))]
+async fn connect_kv(_url: &str) -> Result<Box<dyn AgentDB>> {
+    Err(AgentDbError::Unsupported(
+        "agentdb-kv driver feature not enabled".to_string(),
+    ))
+}
+
+#[cfg(feature = "agentdb-graph")]
+async fn connect_graph(url: &str) -> Result<Box<dyn AgentDB>> {
+    agentdb_graph::connect(url).await
+}
+
+#[cfg(not(feature = "agentdb-graph"))]
+async fn connect_graph(_url: &str) -> Result<Box<dyn AgentDB>> {
+    Err(AgentDbError::Unsupported(
+        "agentdb-graph driver feature not enabled".to_string(),
+    ))
+}
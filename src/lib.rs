@@ -6,10 +6,24 @@
 //!
 //! ## Architecture
 //!
+//! This crate is a thin façade: the traits live in the driver-free
+//! [`agentdb_core`] crate, and concrete backends are separately-compilable
+//! crates selected via Cargo features:
+//!
 //! ```text
-//! AgentFS → AgentDB → (AgentSQL | AgentKV | AgentGraph) → Concrete Backends
+//! AgentFS → agentdb (this crate: connect/registry)
+//!               ├─ agentdb-core  (AgentDB, Capabilities, Transaction, Value, error)
+//!               ├─ agentdb-sql   (feature "agentdb-sql")
+//!               ├─ agentdb-kv    (feature "agentdb-kv", default on)
+//!               └─ agentdb-graph (feature "agentdb-graph")
 //! ```
 //!
+//! The async runtime (`runtime-tokio` / `runtime-async-std`) and TLS
+//! (`tls-rustls` / `tls-native-tls` / `tls-none`) are themselves Cargo
+//! features, forwarded to whichever driver crates are enabled, so a build
+//! that only needs the KV path with no TLS doesn't pull in SQL parsers or a
+//! TLS stack. See [`connect`] for the scheme-based dispatch entry point.
+//!
 //! ## Core Traits
 //!
 //! - [`AgentDB`]: Main trait for database operations (CRUD, transactions, queries)
@@ -32,75 +46,16 @@
 //! }
 //! ```
 
-use std::fmt;
-
-pub mod error;
-pub mod traits;
-
-pub use error::{AgentDbError, Result};
-pub use traits::{AgentDB, Capabilities, DefaultCapabilities, Row, Transaction, QueryResult, ScanResult};
-
-/// Backend family identifier
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum BackendFamily {
-    /// SQL-based backends (SQLite, PostgreSQL, MySQL)
-    Sql,
-    /// Key-value backends (Redis, KeyDB, FoundationDB)
-    Kv,
-    /// Graph backends (Neo4j, Dgraph, TigerGraph)
-    Graph,
-}
-
-impl fmt::Display for BackendFamily {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            BackendFamily::Sql => write!(f, "SQL"),
-            BackendFamily::Kv => write!(f, "KeyValue"),
-            BackendFamily::Graph => write!(f, "Graph"),
-        }
-    }
-}
-
-/// Value type for database operations
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Value(Vec<u8>);
-
-impl Value {
-    /// Create a new value from bytes
-    pub fn new(data: Vec<u8>) -> Self {
-        Self(data)
-    }
-
-    /// Create a value from a byte slice
-    pub fn from_slice(data: &[u8]) -> Self {
-        Self(data.to_vec())
-    }
-
-    /// Get the value as bytes
-    pub fn as_bytes(&self) -> &[u8] {
-        &self.0
-    }
-
-    /// Convert value into bytes
-    pub fn into_bytes(self) -> Vec<u8> {
-        self.0
-    }
-}
-
-impl From<Vec<u8>> for Value {
-    fn from(data: Vec<u8>) -> Self {
-        Self(data)
-    }
-}
-
-impl From<&[u8]> for Value {
-    fn from(data: &[u8]) -> Self {
-        Self(data.to_vec())
-    }
-}
-
-impl AsRef<[u8]> for Value {
-    fn as_ref(&self) -> &[u8] {
-        &self.0
-    }
-}
+pub mod connect;
+
+pub use connect::connect;
+pub use agentdb_core::error::{AgentDbError, Result};
+pub use agentdb_core::migrate::{Migration, MigrationStatus, Migrator};
+pub use agentdb_core::pool::{Pool, PoolBuilder, PooledConnection};
+pub use agentdb_core::prune::PruningMode;
+pub use agentdb_core::statement::{Statement, StatementCache};
+pub use agentdb_core::traits::{
+    AgentDB, Capabilities, DefaultCapabilities, QueryResult, Row, ScanResult, Transaction,
+};
+pub use agentdb_core::transaction::TransactionDepth;
+pub use agentdb_core::{BackendFamily, Value};